@@ -1,22 +1,44 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use std::{sync::LazyLock, time::Duration};
+use std::{collections::VecDeque, sync::LazyLock, time::Duration};
 
-use crate::{config::Config, fl};
+use crate::{
+    config::{Config, MissedTickPolicy, ALERT_HYSTERESIS_PERCENT},
+    fl,
+};
 use cosmic::{
     applet::padded_control,
     cosmic_config::{self, CosmicConfigEntry},
-    iced::{stream, window, Subscription},
+    iced::{stream, window, Alignment, Length, Subscription},
     iced_widget::column,
     iced_winit::commands::popup::{destroy_popup, get_popup},
     prelude::*,
-    widget::{autosize, button, checkbox, container, Id, Row},
+    widget::{autosize, button, checkbox, container, dropdown, slider, text, Id, Row, Space},
 };
 use futures_util::SinkExt;
-use tokio::{select, sync::broadcast, time::interval};
+use notify_rust::Notification;
+use tokio::{
+    select,
+    sync::broadcast,
+    task,
+    time::{interval, MissedTickBehavior},
+};
+
+impl From<MissedTickPolicy> for MissedTickBehavior {
+    fn from(policy: MissedTickPolicy) -> Self {
+        match policy {
+            MissedTickPolicy::Skip => MissedTickBehavior::Skip,
+            MissedTickPolicy::Delay => MissedTickBehavior::Delay,
+            MissedTickPolicy::Burst => MissedTickBehavior::Burst,
+        }
+    }
+}
 
 static AUTOSIZE_MAIN_ID: LazyLock<Id> = LazyLock::new(|| Id::new("autosize-main"));
 
+static MISSED_TICK_POLICY_LABELS: LazyLock<Vec<&'static str>> =
+    LazyLock::new(|| MissedTickPolicy::ALL.iter().map(|p| p.label()).collect());
+
 /// The application model stores app-specific state used to describe its interface and
 /// drive its logic.
 pub struct UsageApp {
@@ -26,7 +48,21 @@ pub struct UsageApp {
     config: Config,
     usage_info: UsageInfo,
     popup: Option<window::Id>,
-    update_stats_tx: broadcast::Sender<(UsageElement, bool)>,
+    /// Broadcasts the whole `Config` to the running sampler subscription whenever it
+    /// changes, whether from a widget in this popup or an external edit picked up by
+    /// `watch_config`. The sampler always resamples against the latest snapshot rather
+    /// than a separately-maintained copy.
+    config_tx: broadcast::Sender<Config>,
+    alert_state: AlertState,
+}
+
+/// Tracks which metrics are currently past their alert threshold, so a notification
+/// only fires on the rising edge (below -> above) rather than on every tick.
+#[derive(Debug, Default, Clone, Copy)]
+struct AlertState {
+    cpu: bool,
+    memory: bool,
+    swap: bool,
 }
 
 /// Messages emitted by the application and its widgets.
@@ -37,9 +73,44 @@ pub enum Message {
         cpu: Option<f32>,
         mem: Option<f32>,
         swap: Option<f32>,
+        network_down: Option<f32>,
+        network_up: Option<f32>,
+        disk_read: Option<f32>,
+        disk_write: Option<f32>,
+        per_core: Option<Vec<f32>>,
     },
     TogglePopup,
     ToggleElement(UsageElement),
+    SetRefreshInterval(u64),
+    SetMissedTickPolicy(MissedTickPolicy),
+    ToggleShowPerCore,
+    SetCpuAlertThreshold(Option<u8>),
+    SetMemoryAlertThreshold(Option<u8>),
+    SetSwapAlertThreshold(Option<u8>),
+}
+
+/// Alert threshold a metric's "Notify above" checkbox sets each time it's enabled.
+const DEFAULT_ALERT_PERCENT: u8 = 90;
+
+impl UsageApp {
+    /// Persists the current config to disk and broadcasts it to the running sampler
+    /// subscription, so every change made from a widget in this popup keeps the sampler
+    /// in sync. External edits picked up by `watch_config` are re-broadcast by the
+    /// `UpdateConfig` arm of `update` instead, without being persisted again.
+    fn broadcast_config(&self) -> Task<cosmic::Action<Message>> {
+        if let Ok(config) = cosmic_config::Config::new(Self::APP_ID, Config::VERSION) {
+            // If writing the config fails, we still want to continue.
+            // If I start using tracing, then I'll want to log something.
+            let _ = self.config.write_entry(&config);
+        }
+
+        let config_tx = self.config_tx.clone();
+        let config = self.config.clone();
+        Task::future(async move {
+            _ = config_tx.send(config);
+        })
+        .discard()
+    }
 }
 
 /// Create a COSMIC application from the app model
@@ -69,7 +140,7 @@ impl cosmic::Application for UsageApp {
         core: cosmic::Core,
         _flags: Self::Flags,
     ) -> (Self, Task<cosmic::Action<Self::Message>>) {
-        let (update_stats_to_watch_tx, _) = broadcast::channel(16);
+        let (config_tx, _) = broadcast::channel(16);
 
         // Construct the app model with the runtime's core.
         let app = UsageApp {
@@ -83,7 +154,8 @@ impl cosmic::Application for UsageApp {
                 .unwrap_or_default(),
             usage_info: Default::default(),
             popup: None,
-            update_stats_tx: update_stats_to_watch_tx,
+            config_tx,
+            alert_state: AlertState::default(),
         };
 
         (app, Task::none())
@@ -96,26 +168,51 @@ impl cosmic::Application for UsageApp {
     fn view(&self) -> Element<Self::Message> {
         let mut row = Row::new().spacing(5);
         if self.config.cpu_enabled {
-            let cpu = self
-                .core
-                .applet
-                .text(fl!("cpu", cpu = ((self.usage_info.cpu) as u8)));
+            let mut cpu = self.core.applet.text(fl!(
+                "cpu",
+                cpu = (UsageInfo::latest(&self.usage_info.cpu) as u8)
+            ));
+            if self.alert_state.cpu {
+                cpu = cpu.class(cosmic::theme::Text::Accent);
+            }
             row = row.push(cpu);
         }
         if self.config.memory_enabled {
-            let memory = self
-                .core
-                .applet
-                .text(fl!("memory", mem = ((self.usage_info.memory * 100.) as u8)));
+            let mut memory = self.core.applet.text(fl!(
+                "memory",
+                mem = ((UsageInfo::latest(&self.usage_info.memory) * 100.) as u8)
+            ));
+            if self.alert_state.memory {
+                memory = memory.class(cosmic::theme::Text::Accent);
+            }
             row = row.push(memory);
         }
         if self.config.swap_enabled {
-            let swap = self
-                .core
-                .applet
-                .text(fl!("swap", swap = ((self.usage_info.swap * 100.) as u8)));
+            let mut swap = self.core.applet.text(fl!(
+                "swap",
+                swap = ((UsageInfo::latest(&self.usage_info.swap) * 100.) as u8)
+            ));
+            if self.alert_state.swap {
+                swap = swap.class(cosmic::theme::Text::Accent);
+            }
             row = row.push(swap);
         };
+        if self.config.network_enabled {
+            let network = self.core.applet.text(fl!(
+                "network",
+                down = format_rate(UsageInfo::latest(&self.usage_info.network_down)),
+                up = format_rate(UsageInfo::latest(&self.usage_info.network_up))
+            ));
+            row = row.push(network);
+        }
+        if self.config.disk_io_enabled {
+            let disk_io = self.core.applet.text(fl!(
+                "disk_io",
+                read = format_rate(UsageInfo::latest(&self.usage_info.disk_read)),
+                write = format_rate(UsageInfo::latest(&self.usage_info.disk_write))
+            ));
+            row = row.push(disk_io);
+        }
 
         let btn = button::custom(row)
             .on_press(Message::TogglePopup)
@@ -125,17 +222,83 @@ impl cosmic::Application for UsageApp {
     }
 
     fn view_window(&self, _id: window::Id) -> Element<Self::Message> {
-        let col = column![
+        let mut col = column![
             checkbox("CPU", self.config.cpu_enabled)
                 .on_toggle(|_| Message::ToggleElement(UsageElement::Cpu)),
+        ];
+        if self.config.cpu_enabled {
+            col = col.push(sparkline(&self.usage_info.cpu, 100.0));
+            col = col.push(
+                checkbox("Show per-core", self.config.show_per_core)
+                    .on_toggle(|_| Message::ToggleShowPerCore),
+            );
+            if self.config.show_per_core {
+                col = col.push(per_core_bars(&self.usage_info.per_core));
+            }
+            col = col.push(alert_control(
+                self.config.cpu_alert_percent,
+                Message::SetCpuAlertThreshold,
+            ));
+        }
+        col = col.push(
             checkbox("Memory", self.config.memory_enabled)
                 .on_toggle(|_| Message::ToggleElement(UsageElement::Memory)),
+        );
+        if self.config.memory_enabled {
+            col = col.push(sparkline(&self.usage_info.memory, 1.0));
+            col = col.push(alert_control(
+                self.config.memory_alert_percent,
+                Message::SetMemoryAlertThreshold,
+            ));
+        }
+        col = col.push(
             checkbox("Swap", self.config.swap_enabled)
                 .on_toggle(|_| Message::ToggleElement(UsageElement::Swap)),
-        ]
-        .spacing(2)
-        .apply(container)
-        .apply(padded_control);
+        );
+        if self.config.swap_enabled {
+            col = col.push(sparkline(&self.usage_info.swap, 1.0));
+            col = col.push(alert_control(
+                self.config.swap_alert_percent,
+                Message::SetSwapAlertThreshold,
+            ));
+        }
+        col = col.push(
+            checkbox("Network", self.config.network_enabled)
+                .on_toggle(|_| Message::ToggleElement(UsageElement::Network)),
+        );
+        if self.config.network_enabled {
+            col = col.push(autoscaled_sparkline(&self.usage_info.network_down));
+            col = col.push(autoscaled_sparkline(&self.usage_info.network_up));
+        }
+        col = col.push(
+            checkbox("Disk I/O", self.config.disk_io_enabled)
+                .on_toggle(|_| Message::ToggleElement(UsageElement::DiskIo)),
+        );
+        if self.config.disk_io_enabled {
+            col = col.push(autoscaled_sparkline(&self.usage_info.disk_read));
+            col = col.push(autoscaled_sparkline(&self.usage_info.disk_write));
+        }
+        let col = col
+            .push(text::body(format!(
+                "Refresh interval: {} ms",
+                self.config.refresh_interval_ms
+            )))
+            .push(
+                slider(100..=5000, self.config.refresh_interval_ms as u32, |ms| {
+                    Message::SetRefreshInterval(ms as u64)
+                })
+                .step(100u32),
+            )
+            .push(dropdown(
+                &MISSED_TICK_POLICY_LABELS,
+                MissedTickPolicy::ALL
+                    .iter()
+                    .position(|p| *p == self.config.missed_tick_policy),
+                |i| Message::SetMissedTickPolicy(MissedTickPolicy::ALL[i]),
+            ))
+            .spacing(2)
+            .apply(container)
+            .apply(padded_control);
         self.core.applet.popup_container(col).into()
     }
 
@@ -145,23 +308,37 @@ impl cosmic::Application for UsageApp {
     /// emit messages to the application through a channel. They are started at the
     /// beginning of the application, and persist through its lifetime.
     fn subscription(&self) -> Subscription<Self::Message> {
-        let mut update_stats_rx = self.update_stats_tx.subscribe();
+        let mut config_rx = self.config_tx.subscribe();
         let mut config = self.config.clone();
 
         let sysinfo = Subscription::run_with_id(
             "sysinfo-sub",
             stream::channel(1, async move |mut output| {
                 let mut sys = sysinfo::System::new();
-                let mut interval = interval(Duration::from_secs(1));
-                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                let mut networks = sysinfo::Networks::new_with_refreshed_list();
+                let mut disks = sysinfo::Disks::new_with_refreshed_list();
+                let mut prev_network_totals: Option<(u64, u64)> = None;
+                let mut last_tick_at = tokio::time::Instant::now();
+                let mut interval = interval(Duration::from_millis(config.refresh_interval_ms));
+                interval.set_missed_tick_behavior(config.missed_tick_policy.into());
                 loop {
                     select! {
                         _ = interval.tick() => {
-                            let cpu = config.cpu_enabled.then(|| {
+                            let now = tokio::time::Instant::now();
+                            let elapsed_secs = (now - last_tick_at).as_secs_f32().max(f32::EPSILON);
+                            last_tick_at = now;
+
+                            let (cpu, per_core) = if config.cpu_enabled {
                                 sys.refresh_cpu_usage();
                                 let cpus = sys.cpus();
-                                cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len() as f32
-                            });
+                                let avg = cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len() as f32;
+                                let per_core = config.show_per_core.then(|| {
+                                    cpus.iter().map(|cpu| cpu.cpu_usage()).collect()
+                                });
+                                (Some(avg), per_core)
+                            } else {
+                                (None, None)
+                            };
 
                             if config.memory_enabled || config.swap_enabled {
                                 sys.refresh_memory();
@@ -174,21 +351,60 @@ impl cosmic::Application for UsageApp {
                                 1. - sys.free_swap() as f32 / sys.total_swap() as f32
                             });
 
+                            let (network_down, network_up) = if config.network_enabled {
+                                networks.refresh(true);
+                                let totals = networks.iter().fold((0u64, 0u64), |(rx, tx), (_, data)| {
+                                    (rx + data.total_received(), tx + data.total_transmitted())
+                                });
+                                let rates = prev_network_totals.map(|(prev_rx, prev_tx)| {
+                                    (
+                                        totals.0.saturating_sub(prev_rx) as f32 / elapsed_secs,
+                                        totals.1.saturating_sub(prev_tx) as f32 / elapsed_secs,
+                                    )
+                                }).unwrap_or((0.0, 0.0));
+                                prev_network_totals = Some(totals);
+                                (Some(rates.0), Some(rates.1))
+                            } else {
+                                prev_network_totals = None;
+                                (None, None)
+                            };
+
+                            let (disk_read, disk_write) = if config.disk_io_enabled {
+                                disks.refresh(true);
+                                let (read, write) = disks.iter().fold((0u64, 0u64), |(read, write), disk| {
+                                    let usage = disk.usage();
+                                    (read + usage.read_bytes, write + usage.written_bytes)
+                                });
+                                (
+                                    Some(read as f32 / elapsed_secs),
+                                    Some(write as f32 / elapsed_secs),
+                                )
+                            } else {
+                                (None, None)
+                            };
+
                             let message = Message::UsageUpdate {
                                 cpu,
                                 mem,
                                 swap,
+                                network_down,
+                                network_up,
+                                disk_read,
+                                disk_write,
+                                per_core,
                             };
 
                             output.send(message).await.unwrap();
                         }
 
-                        Ok((usage, enabled)) = update_stats_rx.recv() => {
-                            match usage {
-                                UsageElement::Cpu => config.cpu_enabled = enabled,
-                                UsageElement::Memory => config.memory_enabled = enabled,
-                                UsageElement::Swap => config.swap_enabled = enabled,
+                        Ok(new_config) = config_rx.recv() => {
+                            if new_config.refresh_interval_ms != config.refresh_interval_ms {
+                                interval = tokio::time::interval(Duration::from_millis(new_config.refresh_interval_ms));
+                                interval.set_missed_tick_behavior(new_config.missed_tick_policy.into());
+                            } else if new_config.missed_tick_policy != config.missed_tick_policy {
+                                interval.set_missed_tick_behavior(new_config.missed_tick_policy.into());
                             }
+                            config = new_config;
                         }
                     }
                 }
@@ -218,19 +434,86 @@ impl cosmic::Application for UsageApp {
         match message {
             Message::UpdateConfig(config) => {
                 self.config = config;
-                Task::none()
+                let config_tx = self.config_tx.clone();
+                let config = self.config.clone();
+                // Re-broadcast rather than calling `broadcast_config`: this update may have
+                // come from an external edit, so there's nothing new to persist.
+                Task::future(async move {
+                    _ = config_tx.send(config);
+                })
+                .discard()
             }
-            Message::UsageUpdate { cpu, mem, swap } => {
+            Message::UsageUpdate {
+                cpu,
+                mem,
+                swap,
+                network_down,
+                network_up,
+                disk_read,
+                disk_write,
+                per_core,
+            } => {
+                let capacity = self.config.history_depth.max(1);
+                let mut alerts = Vec::new();
                 if let Some(cpu) = cpu {
-                    self.usage_info.cpu = cpu;
+                    UsageInfo::record(&mut self.usage_info.cpu, cpu, capacity);
+                    alerts.extend(check_alert(
+                        &mut self.alert_state.cpu,
+                        "CPU",
+                        cpu,
+                        self.config.cpu_alert_percent,
+                    ));
                 }
                 if let Some(mem) = mem {
-                    self.usage_info.memory = mem;
+                    UsageInfo::record(&mut self.usage_info.memory, mem, capacity);
+                    alerts.extend(check_alert(
+                        &mut self.alert_state.memory,
+                        "Memory",
+                        mem * 100.,
+                        self.config.memory_alert_percent,
+                    ));
                 }
                 if let Some(swap) = swap {
-                    self.usage_info.swap = swap;
+                    UsageInfo::record(&mut self.usage_info.swap, swap, capacity);
+                    alerts.extend(check_alert(
+                        &mut self.alert_state.swap,
+                        "Swap",
+                        swap * 100.,
+                        self.config.swap_alert_percent,
+                    ));
+                }
+                if let Some(network_down) = network_down {
+                    UsageInfo::record(&mut self.usage_info.network_down, network_down, capacity);
+                }
+                if let Some(network_up) = network_up {
+                    UsageInfo::record(&mut self.usage_info.network_up, network_up, capacity);
+                }
+                if let Some(disk_read) = disk_read {
+                    UsageInfo::record(&mut self.usage_info.disk_read, disk_read, capacity);
+                }
+                if let Some(disk_write) = disk_write {
+                    UsageInfo::record(&mut self.usage_info.disk_write, disk_write, capacity);
+                }
+                if let Some(per_core) = per_core {
+                    self.usage_info.per_core = per_core;
+                }
+
+                if alerts.is_empty() {
+                    Task::none()
+                } else {
+                    Task::future(async move {
+                        for body in alerts {
+                            let _ = task::spawn_blocking(move || {
+                                Notification::new()
+                                    .summary("cosmic-applet-usage")
+                                    .body(&body)
+                                    .show()
+                            })
+                            .await;
+                        }
+                    })
+                    .discard()
                 }
-                Task::none()
             }
             Message::TogglePopup => {
                 if let Some(id) = self.popup.take() {
@@ -250,31 +533,66 @@ impl cosmic::Application for UsageApp {
                 }
             }
             Message::ToggleElement(e) => {
-                let enabled = match e {
+                match e {
                     UsageElement::Cpu => {
                         self.config.cpu_enabled = !self.config.cpu_enabled;
-                        self.config.cpu_enabled
+                        if !self.config.cpu_enabled {
+                            self.alert_state.cpu = false;
+                        }
                     }
                     UsageElement::Memory => {
                         self.config.memory_enabled = !self.config.memory_enabled;
-                        self.config.memory_enabled
+                        if !self.config.memory_enabled {
+                            self.alert_state.memory = false;
+                        }
                     }
                     UsageElement::Swap => {
                         self.config.swap_enabled = !self.config.swap_enabled;
-                        self.config.swap_enabled
+                        if !self.config.swap_enabled {
+                            self.alert_state.swap = false;
+                        }
+                    }
+                    UsageElement::Network => {
+                        self.config.network_enabled = !self.config.network_enabled
+                    }
+                    UsageElement::DiskIo => {
+                        self.config.disk_io_enabled = !self.config.disk_io_enabled
                     }
                 };
-                if let Ok(config) = cosmic_config::Config::new(Self::APP_ID, Config::VERSION) {
-                    // If writing the config fails, we still want to continue.
-                    // If I start using tracing, then I'll want to log something.
-                    let _ = self.config.write_entry(&config);
+                self.broadcast_config()
+            }
+            Message::SetRefreshInterval(ms) => {
+                self.config.refresh_interval_ms = ms;
+                self.broadcast_config()
+            }
+            Message::SetMissedTickPolicy(policy) => {
+                self.config.missed_tick_policy = policy;
+                self.broadcast_config()
+            }
+            Message::ToggleShowPerCore => {
+                self.config.show_per_core = !self.config.show_per_core;
+                self.broadcast_config()
+            }
+            Message::SetCpuAlertThreshold(threshold) => {
+                self.config.cpu_alert_percent = threshold;
+                if threshold.is_none() {
+                    self.alert_state.cpu = false;
                 }
-
-                let update_stats_tx = self.update_stats_tx.clone();
-                Task::future(async move {
-                    _ = update_stats_tx.send((e, enabled));
-                })
-                .discard()
+                self.broadcast_config()
+            }
+            Message::SetMemoryAlertThreshold(threshold) => {
+                self.config.memory_alert_percent = threshold;
+                if threshold.is_none() {
+                    self.alert_state.memory = false;
+                }
+                self.broadcast_config()
+            }
+            Message::SetSwapAlertThreshold(threshold) => {
+                self.config.swap_alert_percent = threshold;
+                if threshold.is_none() {
+                    self.alert_state.swap = false;
+                }
+                self.broadcast_config()
             }
         }
     }
@@ -284,11 +602,97 @@ impl cosmic::Application for UsageApp {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 struct UsageInfo {
-    cpu: f32,
-    memory: f32,
-    swap: f32,
+    cpu: VecDeque<f32>,
+    memory: VecDeque<f32>,
+    swap: VecDeque<f32>,
+    network_down: VecDeque<f32>,
+    network_up: VecDeque<f32>,
+    disk_read: VecDeque<f32>,
+    disk_write: VecDeque<f32>,
+    per_core: Vec<f32>,
+}
+
+impl UsageInfo {
+    /// Records `value` into `history`, evicting the oldest sample once `capacity` is
+    /// exceeded so the buffer stays a fixed-size window of recent usage.
+    fn record(history: &mut VecDeque<f32>, value: f32, capacity: usize) {
+        history.push_back(value);
+        while history.len() > capacity {
+            history.pop_front();
+        }
+    }
+
+    fn latest(history: &VecDeque<f32>) -> f32 {
+        history.back().copied().unwrap_or(0.0)
+    }
+}
+
+/// Like [`sparkline`], but scales against the largest sample currently in `history`
+/// (never less than 1.0) instead of a fixed bound. Used for rate metrics (network/disk I/O) which have no
+/// natural 0-100 range to plot against.
+fn autoscaled_sparkline<Message>(history: &VecDeque<f32>) -> Element<'static, Message> {
+    let max = history.iter().copied().fold(1.0_f32, f32::max);
+    sparkline(history, max)
+}
+
+/// Renders the enable checkbox and, once enabled, a percent slider for a metric's
+/// alert threshold. `on_change` builds the message for whatever the user set.
+fn alert_control<Message: Clone + 'static>(
+    threshold: Option<u8>,
+    on_change: impl Fn(Option<u8>) -> Message + Copy + 'static,
+) -> Element<'static, Message> {
+    let mut col = column![checkbox("Notify above", threshold.is_some()).on_toggle(move |enabled| {
+        on_change(enabled.then_some(threshold.unwrap_or(DEFAULT_ALERT_PERCENT)))
+    })];
+    if let Some(threshold) = threshold {
+        col = col.push(
+            Row::new()
+                .spacing(5)
+                .push(slider(0..=100, threshold, move |t| on_change(Some(t))))
+                .push(text::body(format!("{threshold}%"))),
+        );
+    }
+    col.into()
+}
+
+/// Renders one bottom-aligned bar per logical CPU, scaled against 0-100%.
+fn per_core_bars<Message>(usages: &[f32]) -> Element<'static, Message> {
+    const BAR_WIDTH: f32 = 6.0;
+    const HEIGHT: f32 = 30.0;
+
+    let mut row = Row::new()
+        .spacing(1)
+        .height(Length::Fixed(HEIGHT))
+        .align_y(Alignment::End);
+    for &usage in usages {
+        let bar_height = (usage / 100.0).clamp(0.0, 1.0) * HEIGHT;
+        row = row.push(
+            container(Space::new(Length::Fixed(BAR_WIDTH), Length::Fixed(bar_height)))
+                .class(cosmic::theme::Container::Primary),
+        );
+    }
+    row.into()
+}
+
+/// Renders `history` as a row of bottom-aligned bars, each scaled against `max`.
+fn sparkline<Message>(history: &VecDeque<f32>, max: f32) -> Element<'static, Message> {
+    const BAR_WIDTH: f32 = 3.0;
+    const HEIGHT: f32 = 30.0;
+
+    let mut row = Row::new()
+        .spacing(1)
+        .height(Length::Fixed(HEIGHT))
+        .align_y(Alignment::End);
+    for &value in history {
+        let bar_height = (value / max).clamp(0.0, 1.0) * HEIGHT;
+        row = row.push(
+            container(Space::new(Length::Fixed(BAR_WIDTH), Length::Fixed(bar_height)))
+                .class(cosmic::theme::Container::Primary),
+        );
+    }
+    row.into()
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -296,4 +700,44 @@ pub enum UsageElement {
     Cpu,
     Memory,
     Swap,
+    Network,
+    DiskIo,
+}
+
+/// Checks `value` (0-100 scale) against `threshold`, flips `alerting` on the rising
+/// edge, and returns a notification body for the caller to dispatch. Clears once
+/// usage drops back below `threshold - ALERT_HYSTERESIS_PERCENT` so a value hovering
+/// right at the line doesn't re-fire every tick.
+fn check_alert(alerting: &mut bool, label: &str, value: f32, threshold: Option<u8>) -> Option<String> {
+    let Some(threshold) = threshold else {
+        *alerting = false;
+        return None;
+    };
+
+    if *alerting {
+        if value < threshold as f32 - ALERT_HYSTERESIS_PERCENT as f32 {
+            *alerting = false;
+        }
+        None
+    } else if value >= threshold as f32 {
+        *alerting = true;
+        Some(format!(
+            "{label} usage is at {value:.0}%, above the {threshold}% alert threshold"
+        ))
+    } else {
+        None
+    }
+}
+
+/// Formats a byte rate with an adaptively scaled unit, e.g. `1.00 KiB/s`.
+fn format_rate(bytes_per_sec: f32) -> String {
+    const UNITS: [&str; 5] = ["B/s", "KiB/s", "MiB/s", "GiB/s", "TiB/s"];
+
+    let mut value = bytes_per_sec.max(0.0);
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
 }
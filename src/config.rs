@@ -1,11 +1,82 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
+#[derive(Debug, Clone, CosmicConfigEntry, Eq, PartialEq)]
 #[version = 1]
 pub struct Config {
     pub cpu_enabled: bool,
     pub memory_enabled: bool,
     pub swap_enabled: bool,
+    pub network_enabled: bool,
+    pub disk_io_enabled: bool,
+    pub refresh_interval_ms: u64,
+    pub missed_tick_policy: MissedTickPolicy,
+    /// Number of samples kept per metric for the popup's sparkline graphs.
+    pub history_depth: usize,
+    /// Whether the popup shows a bar per logical CPU in addition to the average.
+    pub show_per_core: bool,
+    /// Usage percent above which a desktop notification is fired for CPU. `None`
+    /// disables the alert.
+    pub cpu_alert_percent: Option<u8>,
+    /// Usage percent above which a desktop notification is fired for memory. `None`
+    /// disables the alert.
+    pub memory_alert_percent: Option<u8>,
+    /// Usage percent above which a desktop notification is fired for swap. `None`
+    /// disables the alert.
+    pub swap_alert_percent: Option<u8>,
+}
+
+/// How far usage must drop back below an alert threshold, in percentage points,
+/// before the alert is allowed to fire again. Prevents notification spam while usage
+/// hovers right around the threshold.
+pub const ALERT_HYSTERESIS_PERCENT: u8 = 5;
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            cpu_enabled: false,
+            memory_enabled: false,
+            swap_enabled: false,
+            network_enabled: false,
+            disk_io_enabled: false,
+            refresh_interval_ms: 1000,
+            missed_tick_policy: MissedTickPolicy::default(),
+            history_depth: 60,
+            show_per_core: false,
+            cpu_alert_percent: None,
+            memory_alert_percent: None,
+            swap_alert_percent: None,
+        }
+    }
+}
+
+/// How the sampler's `tokio::time::Interval` should behave when a tick is missed,
+/// e.g. because the machine was suspended or the executor was under load.
+///
+/// Mirrors `tokio::time::MissedTickBehavior`, which isn't `Serialize`/`Deserialize`,
+/// so it can be stored in `Config` and converted at subscription start.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum MissedTickPolicy {
+    /// Ticks that were missed are skipped; the next tick fires on schedule.
+    Skip,
+    /// The first missed tick fires immediately; subsequent ticks are delayed by the
+    /// amount of time that passed.
+    #[default]
+    Delay,
+    /// All missed ticks are fired back-to-back as fast as possible to "catch up".
+    Burst,
+}
+
+impl MissedTickPolicy {
+    pub const ALL: [Self; 3] = [Self::Skip, Self::Delay, Self::Burst];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Skip => "Skip",
+            Self::Delay => "Delay",
+            Self::Burst => "Burst",
+        }
+    }
 }